@@ -13,19 +13,78 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use ahash::RandomState;
 use nautilus_core::string::{pystr_to_string, string_to_pystr};
+use once_cell::sync::Lazy;
 use pyo3::ffi;
-use std::collections::hash_map::DefaultHasher;
 use std::fmt::{Debug, Display, Formatter, Result};
-use std::hash::{Hash, Hasher};
+use std::hash::{BuildHasher, Hash, Hasher};
+
+/// The `aHash` keys used to seed [`position_id_hash`].
+///
+/// Fixed (rather than random) so that a given `PositionId` hashes to the same
+/// `u64` for the lifetime of a process, which Python-side dict/set lookups
+/// depend on. The value is *not* stable across crate versions.
+static HASHER: Lazy<RandomState> =
+    Lazy::new(|| RandomState::with_seeds(0xd6e8_feb8_6659_fd93, 0xa4093, 0x822299f3, 0xb3816cae));
 
 #[repr(C)]
 #[derive(Clone, Hash, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 #[allow(clippy::box_collection)] // C ABI compatibility
 pub struct PositionId {
     value: Box<String>,
 }
 
+/// Represents the reason an identifier value was rejected by a checked constructor.
+///
+/// Mirrors the shape of `uuid::Error`: the invalid character (if any), its
+/// byte offset, and the expected-vs-found length, so callers get an
+/// actionable diagnostic rather than an opaque downstream failure.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum IdError {
+    /// The value was empty, or contained only whitespace.
+    Empty,
+    /// The value contained a character not permitted at the given byte offset.
+    InvalidCharacter { character: char, index: usize },
+    /// The value did not have the expected length for its recognized shape.
+    InvalidLength { expected: usize, found: usize },
+}
+
+impl Display for IdError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            IdError::Empty => write!(f, "identifier value was empty"),
+            IdError::InvalidCharacter { character, index } => {
+                write!(f, "invalid character {character:?} at index {index}")
+            }
+            IdError::InvalidLength { expected, found } => {
+                write!(f, "invalid length, expected {expected} but found {found}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for IdError {}
+
+impl PositionId {
+    /// Returns a validated `PositionId`, rejecting empty or whitespace-only input.
+    ///
+    /// # Errors
+    /// Returns [`IdError::Empty`] if `s` is empty or consists only of whitespace.
+    pub fn new(s: &str) -> std::result::Result<PositionId, IdError> {
+        if s.trim().is_empty() {
+            return Err(IdError::Empty);
+        }
+        Ok(PositionId {
+            value: Box::new(s.to_string()),
+        })
+    }
+}
+
+/// Infallible "trusted input" path for internal construction, use [`PositionId::new`]
+/// to validate untrusted input.
 impl From<&str> for PositionId {
     fn from(s: &str) -> PositionId {
         PositionId {
@@ -70,6 +129,43 @@ pub unsafe extern "C" fn position_id_to_pystr(position_id: &PositionId) -> *mut
     string_to_pystr(position_id.value.as_str())
 }
 
+/// A tagged result of a checked `PositionId` construction, for the Python layer
+/// to turn into either a value or a raised exception.
+#[repr(C)]
+pub struct PositionIdFromPystrResult {
+    /// `1` if `id` is valid, `0` if `error` describes why construction failed.
+    pub success: u8,
+    pub id: PositionId,
+    /// A Python `str` describing the error, or null when `success == 1`.
+    pub error: *mut ffi::PyObject,
+}
+
+/// Returns a validated Nautilus identifier from a Python object pointer, or an
+/// error the Python layer can raise as an exception.
+///
+/// # Safety
+/// - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+#[no_mangle]
+pub unsafe extern "C" fn position_id_from_pystr_checked(
+    ptr: *mut ffi::PyObject,
+) -> PositionIdFromPystrResult {
+    let s = pystr_to_string(ptr);
+    match PositionId::new(&s) {
+        Ok(id) => PositionIdFromPystrResult {
+            success: 1,
+            id,
+            error: std::ptr::null_mut(),
+        },
+        Err(e) => PositionIdFromPystrResult {
+            success: 0,
+            id: PositionId {
+                value: Box::new(String::new()),
+            },
+            error: string_to_pystr(&e.to_string()),
+        },
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn position_id_eq(lhs: &PositionId, rhs: &PositionId) -> u8 {
     (lhs == rhs) as u8
@@ -77,7 +173,7 @@ pub extern "C" fn position_id_eq(lhs: &PositionId, rhs: &PositionId) -> u8 {
 
 #[no_mangle]
 pub extern "C" fn position_id_hash(position_id: &PositionId) -> u64 {
-    let mut h = DefaultHasher::new();
+    let mut h = HASHER.build_hasher();
     position_id.hash(&mut h);
     h.finish()
 }
@@ -87,9 +183,23 @@ pub extern "C" fn position_id_hash(position_id: &PositionId) -> u64 {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use super::PositionId;
+    use super::{IdError, PositionId};
     use crate::identifiers::position_id::position_id_free;
 
+    #[test]
+    fn test_new_rejects_empty() {
+        assert_eq!(PositionId::new(""), Err(IdError::Empty));
+        assert_eq!(PositionId::new("   "), Err(IdError::Empty));
+    }
+
+    #[test]
+    fn test_new_accepts_valid() {
+        assert_eq!(
+            PositionId::new("P-123456789"),
+            Ok(PositionId::from("P-123456789"))
+        );
+    }
+
     #[test]
     fn test_equality() {
         let id1 = PositionId::from("P-123456789");
@@ -113,4 +223,27 @@ mod tests {
 
         position_id_free(id); // No panic
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_json_round_trip() {
+        let id = PositionId::from("P-123456789");
+
+        let serialized = serde_json::to_string(&id).unwrap();
+        let deserialized: PositionId = serde_json::from_str(&serialized).unwrap();
+
+        assert_eq!(serialized, "\"P-123456789\"");
+        assert_eq!(deserialized, id);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_msgpack_round_trip() {
+        let id = PositionId::from("P-123456789");
+
+        let serialized = rmp_serde::to_vec(&id).unwrap();
+        let deserialized: PositionId = rmp_serde::from_slice(&serialized).unwrap();
+
+        assert_eq!(deserialized, id);
+    }
 }